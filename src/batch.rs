@@ -0,0 +1,133 @@
+use bytes::Bytes;
+use serde::Serialize;
+
+use near_jsonrpc_primitives::errors::RpcError;
+use near_jsonrpc_primitives::message::Message;
+
+use crate::transport::Transport;
+use crate::JsonRpcClient;
+
+/// A single sub-request collected into a [`BatchRequest`].
+struct BatchEntry {
+    id: u64,
+    request: Message,
+}
+
+/// Builder that collects several JSON-RPC calls so they can be sent in a single
+/// HTTP POST as a top-level JSON array.
+///
+/// Each call added to the batch is tagged with a distinct, monotonically
+/// incremented `id`. Because a JSON-RPC server is free to return the response
+/// array in any order, the results are re-indexed by `id` on receipt and handed
+/// back to the caller in submission order.
+#[derive(Default)]
+pub struct BatchRequest {
+    next_id: u64,
+    entries: Vec<BatchEntry>,
+}
+
+impl BatchRequest {
+    /// Creates an empty batch.
+    pub fn new() -> Self {
+        BatchRequest::default()
+    }
+
+    /// Adds a call to the batch and returns the index at which its result will
+    /// appear in the [`send_batch`](JsonRpcClient::send_batch) output.
+    pub fn add<P>(&mut self, method: &str, params: P) -> usize
+    where
+        P: Serialize,
+    {
+        let id = self.next_id;
+        self.next_id += 1;
+        let mut request = Message::request(
+            method.to_string(),
+            Some(serde_json::to_value(&params).unwrap()),
+        );
+        // Override the id assigned by `Message::request` with our own counter so
+        // responses can be matched back to submission order.
+        if let Message::Request(ref mut req) = request {
+            req.id = serde_json::Value::from(id);
+        }
+        self.entries.push(BatchEntry { id, request });
+        self.entries.len() - 1
+    }
+
+    /// Number of calls currently queued in the batch.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no calls have been queued.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// The outcome of a single element of a batch: either the raw successful result
+/// value, still awaiting deserialization into the caller's target type, or the
+/// per-element [`RpcError`] returned by the server.
+pub type BatchElement = Result<serde_json::Value, RpcError>;
+
+impl<T: Transport> JsonRpcClient<T> {
+    /// Sends every call queued in `batch` as a single JSON-RPC 2.0 batch request
+    /// and returns one result per call, in submission order.
+    ///
+    /// A failure of an individual element surfaces as an `Err` in that slot; a
+    /// transport or parse failure of the batch as a whole fails the call.
+    pub async fn send_batch(&self, batch: BatchRequest) -> Result<Vec<BatchElement>, RpcError> {
+        if batch.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let ids: Vec<u64> = batch.entries.iter().map(|e| e.id).collect();
+        let payload: Vec<&Message> = batch.entries.iter().map(|e| &e.request).collect();
+        let payload = Bytes::from(serde_json::to_vec(&payload).unwrap());
+
+        let bytes = self
+            .transport
+            .request(payload)
+            .await
+            .map_err(|err| RpcError::new_internal_error(None, format!("{}", err)))?;
+
+        // A batch reply is a top-level JSON array, so parse the whole array
+        // rather than a single `Message` as in the non-batch path.
+        let messages: Vec<Message> = serde_json::from_slice(&bytes)
+            .map_err(|err| RpcError::parse_error(format!("Error {:?} in {:?}", err, bytes)))?;
+
+        // Index each response by its id so order-independent servers are handled.
+        let mut by_id = std::collections::HashMap::new();
+        for msg in messages {
+            if let Message::Response(resp) = msg {
+                if let Some(id) = resp.id.as_u64() {
+                    by_id.insert(id, resp);
+                }
+            }
+        }
+
+        let mut results = Vec::with_capacity(ids.len());
+        for id in ids {
+            let element = match by_id.remove(&id) {
+                Some(resp) => resp.result,
+                None => Err(RpcError::parse_error(format!(
+                    "Missing response for batch element id {}",
+                    id
+                ))),
+            };
+            results.push(element);
+        }
+        Ok(results)
+    }
+}
+
+/// Deserializes a single [`BatchElement`] into the caller's target type `R`,
+/// preserving the per-element error.
+pub fn parse_element<R>(element: BatchElement) -> Result<R, RpcError>
+where
+    R: serde::de::DeserializeOwned,
+{
+    element.and_then(|value| {
+        serde_json::from_value(value)
+            .map_err(|err| RpcError::parse_error(format!("Failed to parse: {:?}", err)))
+    })
+}
@@ -0,0 +1,151 @@
+use serde::de::DeserializeOwned;
+
+use near_jsonrpc_primitives::errors::{RpcError, RpcErrorKind, RpcRequestValidationErrorKind};
+use near_jsonrpc_primitives::message::{from_slice, Message};
+use near_jsonrpc_primitives::types::blocks::RpcBlockError;
+use near_jsonrpc_primitives::types::query::{RpcQueryError, RpcQueryRequest, RpcQueryResponse};
+use near_jsonrpc_primitives::types::transactions::RpcTransactionError;
+use near_primitives::types::{AccountId, BlockReference};
+use near_primitives::views::BlockView;
+
+use bytes::Bytes;
+
+use crate::transport::{Transport, TransportError};
+use crate::types::FinalExecutionOutcomeView;
+use crate::JsonRpcClient;
+
+/// A server-side JSON-RPC error, with the structured `error.data` / `error.cause`
+/// payload that NEAR returns deserialized into the method-specific handler error
+/// `E` (e.g. `UNKNOWN_BLOCK`, `INVALID_ACCOUNT`, `TIMEOUT_ERROR`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonRpcServerError<E> {
+    /// The request failed validation before reaching a handler.
+    RequestValidationError(RpcRequestValidationErrorKind),
+    /// A handler error typed per method (query, tx, block, …). The raw
+    /// [`RpcError`] is retained alongside the typed value for
+    /// forward-compatibility.
+    HandlerError { error: E, raw: RpcError },
+    /// An internal server error; `info` holds its payload rendered as a string.
+    InternalError { info: Option<String> },
+    /// The error could not be classified; the raw `RpcError` is preserved for
+    /// forward-compatibility.
+    NonContextualError(RpcError),
+}
+
+/// Everything that can go wrong performing a typed RPC call: a transport failure,
+/// a failure to parse the wire payload, or a typed server error.
+#[derive(Debug)]
+pub enum JsonRpcError<E> {
+    /// The transport failed to deliver the request or to return a response.
+    TransportError(TransportError),
+    /// The response bytes or result value could not be deserialized.
+    ParseError(String),
+    /// The server returned a (typed) error object.
+    ServerError(JsonRpcServerError<E>),
+}
+
+impl<E> JsonRpcError<E> {
+    /// Returns the typed handler error, if this is one.
+    pub fn handler_error(&self) -> Option<&E> {
+        match self {
+            JsonRpcError::ServerError(JsonRpcServerError::HandlerError { error, .. }) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Converts a raw [`RpcError`] into a typed [`JsonRpcServerError`], deserializing
+/// the structured handler payload into `E` and keeping the raw error around when
+/// it does not match.
+fn convert_error<E: DeserializeOwned>(err: RpcError) -> JsonRpcServerError<E> {
+    match &err.error_struct {
+        Some(RpcErrorKind::RequestValidationError(kind)) => {
+            JsonRpcServerError::RequestValidationError(kind.clone())
+        }
+        Some(RpcErrorKind::HandlerError(value)) => {
+            match serde_json::from_value::<E>(value.clone()) {
+                Ok(typed) => JsonRpcServerError::HandlerError {
+                    error: typed,
+                    raw: err,
+                },
+                Err(_) => JsonRpcServerError::NonContextualError(err),
+            }
+        }
+        Some(RpcErrorKind::InternalError(value)) => JsonRpcServerError::InternalError {
+            info: Some(value.to_string()),
+        },
+        None => JsonRpcServerError::NonContextualError(err),
+    }
+}
+
+impl<T: Transport> JsonRpcClient<T> {
+    /// Performs a JSON-RPC call and parses any server error into the
+    /// method-specific handler error `E`, so callers can `match` on
+    /// `UnknownBlock` vs `InvalidTransaction` rather than string-matching.
+    pub async fn call_typed<P, R, E>(
+        &self,
+        method: &str,
+        params: P,
+    ) -> Result<R, JsonRpcError<E>>
+    where
+        P: serde::Serialize,
+        R: DeserializeOwned + 'static,
+        E: DeserializeOwned,
+    {
+        let request = Message::request(
+            method.to_string(),
+            Some(serde_json::to_value(&params).unwrap()),
+        );
+        let payload = Bytes::from(serde_json::to_vec(&request).unwrap());
+
+        let response = self
+            .transport
+            .request(payload)
+            .await
+            .map_err(JsonRpcError::TransportError)?;
+
+        let message = from_slice(response.to_vec().as_slice())
+            .map_err(|err| JsonRpcError::ParseError(format!("Error {:?} in {:?}", err, response)))?;
+
+        match message {
+            Message::Response(resp) => match resp.result {
+                Ok(value) => serde_json::from_value(value)
+                    .map_err(|err| JsonRpcError::ParseError(format!("Failed to parse: {:?}", err))),
+                Err(err) => Err(JsonRpcError::ServerError(convert_error(err))),
+            },
+            _ => Err(JsonRpcError::ParseError(
+                "Failed to parse JSON RPC response".to_string(),
+            )),
+        }
+    }
+
+    /// Typed variant of [`query`](JsonRpcClient::query): a server error is parsed
+    /// into [`RpcQueryError`] so callers can `match` on `UnknownAccount`,
+    /// `InvalidAccount`, etc.
+    pub async fn query_typed(
+        &self,
+        request: RpcQueryRequest,
+    ) -> Result<RpcQueryResponse, JsonRpcError<RpcQueryError>> {
+        self.call_typed("query", request).await
+    }
+
+    /// Typed variant of [`block`](JsonRpcClient::block): a server error is parsed
+    /// into [`RpcBlockError`] so callers can `match` on `UnknownBlock` directly.
+    pub async fn block_typed(
+        &self,
+        request: BlockReference,
+    ) -> Result<BlockView, JsonRpcError<RpcBlockError>> {
+        self.call_typed("block", request).await
+    }
+
+    /// Typed variant of [`tx`](JsonRpcClient::tx): a server error is parsed into
+    /// [`RpcTransactionError`] so callers can `match` on `InvalidTransaction`,
+    /// `TimeoutError`, etc.
+    pub async fn tx_typed(
+        &self,
+        hash: String,
+        account_id: AccountId,
+    ) -> Result<FinalExecutionOutcomeView, JsonRpcError<RpcTransactionError>> {
+        self.call_typed("tx", (hash, account_id)).await
+    }
+}
@@ -1,10 +1,13 @@
+pub mod batch;
+pub mod errors;
 mod examples;
+pub mod pool;
+pub mod retry;
+pub mod subscribe;
+pub mod transport;
 pub mod types;
 
-use std::str;
-use std::time::Duration;
-
-use reqwest::Client;
+use bytes::Bytes;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -22,6 +25,8 @@ use near_primitives::views::{
 };
 use types::FinalExecutionOutcomeView;
 
+use transport::{HttpTransport, Transport};
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ChunkId {
@@ -29,19 +34,12 @@ pub enum ChunkId {
     Hash(CryptoHash),
 }
 
-/// Timeout for establishing connection.
-const CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
-
 type RpcRequest<T> = Result<T, RpcError>;
 
-/// Prepare a `RPCRequest` with a given client, server address, method and parameters.
-async fn call_method<P, R>(
-    client: &Client,
-    server_addr: &str,
-    method: &str,
-    params: P,
-) -> RpcRequest<R>
+/// Prepare a `RPCRequest` with a given transport, method and parameters.
+async fn call_method<T, P, R>(transport: &T, method: &str, params: P) -> RpcRequest<R>
 where
+    T: Transport + ?Sized,
     P: Serialize,
     R: serde::de::DeserializeOwned + 'static,
 {
@@ -49,33 +47,24 @@ where
         method.to_string(),
         Some(serde_json::to_value(&params).unwrap()),
     );
+    let payload = Bytes::from(serde_json::to_vec(&request).unwrap());
 
-    // TODO: simplify this.
-    let result = client
-        .post(server_addr)
-        .json(&request)
-        .send()
+    let response = transport
+        .request(payload)
         .await
-        .map_err(|err| RpcError::new_internal_error(None, format!("{:?}", err)));
-
-    let resp = match result {
-        Err(why) => Err(why),
-        Ok(resp) => Ok(resp.bytes().await.unwrap()),
-    };
-
-    resp.and_then(|response| {
-        from_slice(response.to_vec().as_slice())
-            .map_err(|err| RpcError::parse_error(format!("Error {:?} in {:?}", err, response)))
-    })
-    .and_then(|msg| match msg {
-        Message::Response(msg) => msg.result.and_then(|v| {
-            serde_json::from_value(v.clone())
-                .map_err(|err| RpcError::parse_error(format!("Failed to parse: {:?}", err)))
-        }),
-        _ => Err(RpcError::parse_error(format!(
-            "Failed to parse JSON RPC response"
-        ))),
-    })
+        .map_err(|err| RpcError::new_internal_error(None, format!("{}", err)))?;
+
+    from_slice(response.to_vec().as_slice())
+        .map_err(|err| RpcError::parse_error(format!("Error {:?} in {:?}", err, response)))
+        .and_then(|msg| match msg {
+            Message::Response(msg) => msg.result.and_then(|v| {
+                serde_json::from_value(v.clone())
+                    .map_err(|err| RpcError::parse_error(format!("Failed to parse: {:?}", err)))
+            }),
+            _ => Err(RpcError::parse_error(
+                "Failed to parse JSON RPC response".to_string(),
+            )),
+        })
 }
 
 /// Expands a variable list of parameters into its serializable form. Is needed to make the params
@@ -89,6 +78,9 @@ macro_rules! expand_params {
 
 /// Generates JSON-RPC 2.0 client structs with automatic serialization
 /// and deserialization. Method calls get correct types automatically.
+///
+/// The generated client is generic over a [`Transport`], defaulting to the
+/// [`HttpTransport`] so existing call sites keep the reqwest-backed behavior.
 macro_rules! jsonrpc_client {
     (
         $(#[$struct_attr:meta])*
@@ -99,15 +91,14 @@ macro_rules! jsonrpc_client {
         )*}
     ) => (
         $(#[$struct_attr])*
-        pub struct $struct_name {
-            pub server_addr: String,
-            pub client: Client,
+        pub struct $struct_name<T = HttpTransport> {
+            pub transport: T,
         }
 
-        impl $struct_name {
+        impl<T: Transport> $struct_name<T> {
             /// Creates a new RPC client backed by the given transport implementation.
-            pub fn new(server_addr: &str, client: Client) -> Self {
-                $struct_name { server_addr: server_addr.to_string(), client }
+            pub fn new(transport: T) -> Self {
+                $struct_name { transport }
             }
 
             $(
@@ -117,7 +108,7 @@ macro_rules! jsonrpc_client {
                 {
                     let method = String::from(stringify!($method));
                     let params = expand_params!($($arg_name,)*);
-                    call_method(&$selff.client, &$selff.server_addr, &method, params).await
+                    call_method(&$selff.transport, &method, params).await
                 }
             )*
         }
@@ -146,7 +137,7 @@ jsonrpc_client!(
     }
 );
 
-impl JsonRpcClient {
+impl<T: Transport> JsonRpcClient<T> {
     /// This is a soft-deprecated method to do query RPC request with a path and data positional
     /// parameters.
     pub async fn query_by_path(
@@ -154,22 +145,22 @@ impl JsonRpcClient {
         path: String,
         data: String,
     ) -> RpcRequest<near_jsonrpc_primitives::types::query::RpcQueryResponse> {
-        call_method(&self.client, &self.server_addr, "query", [path, data]).await
+        call_method(&self.transport, "query", [path, data]).await
     }
 
     pub async fn query(
         &self,
         request: near_jsonrpc_primitives::types::query::RpcQueryRequest,
     ) -> RpcRequest<near_jsonrpc_primitives::types::query::RpcQueryResponse> {
-        call_method(&self.client, &self.server_addr, "query", request).await
+        call_method(&self.transport, "query", request).await
     }
 
     pub async fn block_by_id(&self, block_id: BlockId) -> RpcRequest<BlockView> {
-        call_method(&self.client, &self.server_addr, "block", [block_id]).await
+        call_method(&self.transport, "block", [block_id]).await
     }
 
     pub async fn block(&self, request: BlockReference) -> RpcRequest<BlockView> {
-        call_method(&self.client, &self.server_addr, "block", request).await
+        call_method(&self.transport, "block", request).await
     }
 
     #[allow(non_snake_case)]
@@ -177,13 +168,7 @@ impl JsonRpcClient {
         &self,
         request: RpcStateChangesInBlockByTypeRequest,
     ) -> RpcRequest<RpcStateChangesInBlockByTypeResponse> {
-        call_method(
-            &self.client,
-            &self.server_addr,
-            "EXPERIMENTAL_changes",
-            request,
-        )
-        .await
+        call_method(&self.transport, "EXPERIMENTAL_changes", request).await
     }
 
     #[allow(non_snake_case)]
@@ -191,13 +176,7 @@ impl JsonRpcClient {
         &self,
         request: RpcValidatorsOrderedRequest,
     ) -> RpcRequest<Vec<ValidatorStakeView>> {
-        call_method(
-            &self.client,
-            &self.server_addr,
-            "EXPERIMENTAL_validators_ordered",
-            request,
-        )
-        .await
+        call_method(&self.transport, "EXPERIMENTAL_validators_ordered", request).await
     }
 
     #[allow(non_snake_case)]
@@ -205,13 +184,7 @@ impl JsonRpcClient {
         &self,
         request: near_jsonrpc_primitives::types::receipts::RpcReceiptRequest,
     ) -> RpcRequest<near_jsonrpc_primitives::types::receipts::RpcReceiptResponse> {
-        call_method(
-            &self.client,
-            &self.server_addr,
-            "EXPERIMENTAL_receipt",
-            request,
-        )
-        .await
+        call_method(&self.transport, "EXPERIMENTAL_receipt", request).await
     }
 
     #[allow(non_snake_case)]
@@ -219,25 +192,11 @@ impl JsonRpcClient {
         &self,
         request: near_jsonrpc_primitives::types::config::RpcProtocolConfigRequest,
     ) -> RpcRequest<near_jsonrpc_primitives::types::config::RpcProtocolConfigResponse> {
-        call_method(
-            &self.client,
-            &self.server_addr,
-            "EXPERIMENTAL_protocol_config",
-            request,
-        )
-        .await
+        call_method(&self.transport, "EXPERIMENTAL_protocol_config", request).await
     }
 }
 
-fn create_client() -> Client {
-    Client::builder()
-        .timeout(CONNECT_TIMEOUT)
-        .tcp_keepalive(Duration::from_secs(30))
-        .build()
-        .unwrap_or_default()
-}
-
 /// Create new JSON RPC client that connects to the given address.
-pub fn new_client(server_addr: &str) -> JsonRpcClient {
-    JsonRpcClient::new(server_addr, create_client())
+pub fn new_client(server_addr: &str) -> JsonRpcClient<HttpTransport> {
+    JsonRpcClient::new(HttpTransport::new(server_addr))
 }
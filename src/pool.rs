@@ -0,0 +1,186 @@
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+
+use crate::transport::{HttpTransport, Transport, TransportError};
+use crate::JsonRpcClient;
+
+/// How a [`PooledTransport`] picks which endpoint to try first on each call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndpointStrategy {
+    /// Cycle through endpoints in order.
+    RoundRobin,
+    /// Pick a random endpoint.
+    Random,
+    /// Always prefer the first endpoint, falling back to the rest in order.
+    PrimaryWithFallback,
+}
+
+/// Number of consecutive failures before an endpoint is put into cooldown.
+const FAILURE_THRESHOLD: u32 = 3;
+/// How long an endpoint is skipped once it trips the failure threshold.
+const COOLDOWN: Duration = Duration::from_secs(30);
+
+struct EndpointHealth {
+    consecutive_failures: u32,
+    skip_until: Option<Instant>,
+}
+
+struct Endpoint<T> {
+    transport: T,
+    health: Mutex<EndpointHealth>,
+}
+
+impl<T> Endpoint<T> {
+    fn is_healthy(&self) -> bool {
+        let health = self.health.lock().unwrap();
+        match health.skip_until {
+            Some(until) => Instant::now() >= until,
+            None => true,
+        }
+    }
+
+    fn record_success(&self) {
+        let mut health = self.health.lock().unwrap();
+        health.consecutive_failures = 0;
+        health.skip_until = None;
+    }
+
+    fn record_failure(&self) {
+        let mut health = self.health.lock().unwrap();
+        health.consecutive_failures += 1;
+        if health.consecutive_failures >= FAILURE_THRESHOLD {
+            health.skip_until = Some(Instant::now() + COOLDOWN);
+        }
+    }
+}
+
+/// A [`Transport`] that spreads calls across several backing transports and
+/// transparently fails over to the next healthy one on a transport or 5xx
+/// error, tracking per-endpoint health so a dead node is temporarily skipped.
+pub struct PooledTransport<T> {
+    endpoints: Vec<Endpoint<T>>,
+    strategy: EndpointStrategy,
+    cursor: AtomicUsize,
+    rng_state: AtomicU32,
+}
+
+impl<T> PooledTransport<T> {
+    /// Builds a pool over `transports` using the given selection `strategy`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `transports` is empty — a pool with no endpoints could never
+    /// serve a request and would divide by zero when selecting one.
+    pub fn new(transports: Vec<T>, strategy: EndpointStrategy) -> Self {
+        assert!(
+            !transports.is_empty(),
+            "PooledTransport requires at least one endpoint"
+        );
+        let endpoints = transports
+            .into_iter()
+            .map(|transport| Endpoint {
+                transport,
+                health: Mutex::new(EndpointHealth {
+                    consecutive_failures: 0,
+                    skip_until: None,
+                }),
+            })
+            .collect();
+        PooledTransport {
+            endpoints,
+            strategy,
+            cursor: AtomicUsize::new(0),
+            rng_state: AtomicU32::new(0x9e37_79b9),
+        }
+    }
+
+    /// Order in which endpoints should be tried for the current call.
+    fn order(&self) -> Vec<usize> {
+        let n = self.endpoints.len();
+        let start = match self.strategy {
+            EndpointStrategy::PrimaryWithFallback => 0,
+            EndpointStrategy::RoundRobin => self.cursor.fetch_add(1, Ordering::Relaxed) % n,
+            EndpointStrategy::Random => {
+                // xorshift keeps selection lock-free without pulling in `rand`.
+                let mut x = self.rng_state.load(Ordering::Relaxed);
+                x ^= x << 13;
+                x ^= x >> 17;
+                x ^= x << 5;
+                self.rng_state.store(x, Ordering::Relaxed);
+                (x as usize) % n
+            }
+        };
+        (0..n).map(|i| (start + i) % n).collect()
+    }
+}
+
+/// Whether a failure warrants failing over to another endpoint.
+fn should_failover(err: &TransportError) -> bool {
+    match err {
+        TransportError::Connection(_) | TransportError::Read(_) => true,
+        TransportError::Status { code, .. } => (500..600).contains(code) || *code == 429,
+    }
+}
+
+#[async_trait]
+impl<T: Transport> Transport for PooledTransport<T> {
+    async fn request(&self, payload: Bytes) -> Result<Bytes, TransportError> {
+        let order = self.order();
+        let mut last_err = None;
+        // First pass over healthy endpoints; fall back to all endpoints if every
+        // one is in cooldown so the pool never wedges permanently.
+        for pass in 0..2 {
+            let mut attempted = false;
+            for &idx in &order {
+                let endpoint = &self.endpoints[idx];
+                if pass == 0 && !endpoint.is_healthy() {
+                    continue;
+                }
+                attempted = true;
+                match endpoint.transport.request(payload.clone()).await {
+                    Ok(bytes) => {
+                        endpoint.record_success();
+                        return Ok(bytes);
+                    }
+                    Err(err) => {
+                        endpoint.record_failure();
+                        if !should_failover(&err) {
+                            return Err(err);
+                        }
+                        last_err = Some(err);
+                    }
+                }
+            }
+            // Only stop early when this pass actually dispatched to an endpoint;
+            // if pass 0 skipped everything (all in cooldown), fall through to
+            // pass 1 which ignores health.
+            if attempted {
+                break;
+            }
+        }
+        Err(last_err.unwrap_or_else(|| {
+            TransportError::Connection("no endpoints configured".to_string())
+        }))
+    }
+}
+
+/// A [`JsonRpcClient`] backed by a pool of redundant HTTP endpoints.
+pub type PooledJsonRpcClient = JsonRpcClient<PooledTransport<HttpTransport>>;
+
+/// Creates a high-availability client over several endpoint URLs.
+///
+/// # Panics
+///
+/// Panics if `endpoints` is empty.
+pub fn new_pooled_client(endpoints: &[&str], strategy: EndpointStrategy) -> PooledJsonRpcClient {
+    assert!(
+        !endpoints.is_empty(),
+        "new_pooled_client requires at least one endpoint"
+    );
+    let transports = endpoints.iter().map(|addr| HttpTransport::new(addr)).collect();
+    JsonRpcClient::new(PooledTransport::new(transports, strategy))
+}
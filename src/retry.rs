@@ -0,0 +1,177 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+
+use crate::transport::{HttpTransport, Transport, TransportError};
+use crate::JsonRpcClient;
+
+/// How a [`RetryTransport`] backs off and gives up.
+///
+/// Delays grow exponentially as `min(max_delay, base_delay * 2^attempt)` with up
+/// to `jitter` of uniform random noise added, to avoid synchronized retries
+/// against a rate-limited endpoint.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the initial attempt.
+    pub max_retries: u32,
+    /// Base delay for the first backoff step.
+    pub base_delay: Duration,
+    /// Upper bound on the exponential part of a backoff step and on a
+    /// `Retry-After` hint; jitter is added on top of the capped exponential delay.
+    pub max_delay: Duration,
+    /// Maximum random jitter added to each backoff step.
+    pub jitter: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 5,
+            base_delay: Duration::from_millis(300),
+            max_delay: Duration::from_secs(10),
+            jitter: Duration::from_millis(200),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Backoff delay for the given zero-based `attempt`, honoring a server
+    /// supplied `retry_after` hint when present. `jitter_unit` is a value in
+    /// `[0, 1)` scaling the random jitter component.
+    fn delay_for(&self, attempt: u32, retry_after: Option<Duration>, jitter_unit: f64) -> Duration {
+        if let Some(hint) = retry_after {
+            return hint.min(self.max_delay);
+        }
+        let exp = self
+            .base_delay
+            .checked_mul(1u32 << attempt.min(31))
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        let jitter = if self.jitter.is_zero() {
+            Duration::ZERO
+        } else {
+            self.jitter.mul_f64(jitter_unit)
+        };
+        exp + jitter
+    }
+}
+
+/// Returns `true` for transient conditions worth retrying — connection errors,
+/// timeouts, HTTP 429, and 502/503/504 — and the optional `Retry-After` hint.
+///
+/// Deterministic JSON-RPC application errors never reach here (they are carried
+/// inside a successful HTTP response), so they are never retried.
+fn classify(err: &TransportError) -> (bool, Option<Duration>) {
+    match err {
+        TransportError::Connection(_) | TransportError::Read(_) => (true, None),
+        TransportError::Status {
+            code, retry_after, ..
+        } => {
+            let retryable = matches!(code, 429 | 502 | 503 | 504);
+            (retryable, retry_after.map(Duration::from_secs))
+        }
+    }
+}
+
+/// Wraps another [`Transport`], retrying retryable failures per a [`RetryPolicy`].
+#[derive(Clone)]
+pub struct RetryTransport<T> {
+    inner: T,
+    policy: RetryPolicy,
+    rng_state: std::sync::Arc<AtomicU32>,
+}
+
+impl<T> RetryTransport<T> {
+    /// Wraps `inner` with the given retry `policy`.
+    pub fn new(inner: T, policy: RetryPolicy) -> Self {
+        RetryTransport {
+            inner,
+            policy,
+            rng_state: std::sync::Arc::new(AtomicU32::new(0x9e37_79b9)),
+        }
+    }
+
+    /// Draws a pseudo-random value in `[0, 1)` using the same lock-free xorshift
+    /// as `PooledTransport`, so the series doesn't pull in `rand`.
+    fn jitter_unit(&self) -> f64 {
+        let mut x = self.rng_state.load(Ordering::Relaxed);
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.rng_state.store(x, Ordering::Relaxed);
+        (x as f64) / (u32::MAX as f64 + 1.0)
+    }
+}
+
+#[async_trait]
+impl<T: Transport> Transport for RetryTransport<T> {
+    async fn request(&self, payload: Bytes) -> Result<Bytes, TransportError> {
+        let mut attempt = 0;
+        loop {
+            match self.inner.request(payload.clone()).await {
+                Ok(bytes) => return Ok(bytes),
+                Err(err) => {
+                    let (retryable, retry_after) = classify(&err);
+                    if !retryable || attempt >= self.policy.max_retries {
+                        return Err(err);
+                    }
+                    let delay = self.policy.delay_for(attempt, retry_after, self.jitter_unit());
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+}
+
+/// Builder for a resilient [`JsonRpcClient`] backed by an HTTP transport with a
+/// configurable timeout and retry policy.
+pub struct JsonRpcClientBuilder {
+    server_addr: String,
+    timeout: Duration,
+    tcp_keepalive: Duration,
+    policy: RetryPolicy,
+}
+
+impl JsonRpcClientBuilder {
+    /// Starts a builder targeting `server_addr` with default settings.
+    pub fn new(server_addr: &str) -> Self {
+        JsonRpcClientBuilder {
+            server_addr: server_addr.to_string(),
+            timeout: Duration::from_secs(30),
+            tcp_keepalive: Duration::from_secs(30),
+            policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Sets the request timeout, replacing the previously hardcoded value.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the TCP keepalive interval.
+    pub fn tcp_keepalive(mut self, keepalive: Duration) -> Self {
+        self.tcp_keepalive = keepalive;
+        self
+    }
+
+    /// Sets the retry policy.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Builds the client.
+    pub fn build(self) -> JsonRpcClient<RetryTransport<HttpTransport>> {
+        let client = reqwest::Client::builder()
+            .timeout(self.timeout)
+            .tcp_keepalive(self.tcp_keepalive)
+            .build()
+            .unwrap_or_default();
+        let transport = HttpTransport::with_client(&self.server_addr, client);
+        JsonRpcClient::new(RetryTransport::new(transport, self.policy))
+    }
+}
@@ -0,0 +1,160 @@
+use std::time::Duration;
+
+use async_stream::stream;
+use futures::{Stream, StreamExt};
+
+use near_jsonrpc_primitives::errors::RpcError;
+use near_jsonrpc_primitives::types::changes::{
+    RpcStateChangesInBlockByTypeRequest, RpcStateChangesInBlockByTypeResponse,
+};
+use near_primitives::types::{BlockId, BlockReference, Finality};
+use near_primitives::views::BlockView;
+
+use crate::transport::Transport;
+use crate::JsonRpcClient;
+
+/// Tuning for a polling subscription.
+#[derive(Debug, Clone)]
+pub struct SubscriptionConfig {
+    /// How often the finality tip is polled.
+    pub poll_interval: Duration,
+    /// How many yielded items the subscription may buffer ahead of a slow
+    /// consumer before the poll loop applies backpressure.
+    pub buffer: usize,
+}
+
+impl Default for SubscriptionConfig {
+    fn default() -> Self {
+        SubscriptionConfig {
+            poll_interval: Duration::from_secs(1),
+            buffer: 16,
+        }
+    }
+}
+
+/// Forwards `source` through a bounded channel of `capacity` items, so the poll
+/// loop can run ahead of a slow downstream consumer up to the buffer size.
+fn buffered<S>(source: S, capacity: usize) -> impl Stream<Item = S::Item>
+where
+    S: Stream + Send + 'static,
+    S::Item: Send + 'static,
+{
+    let (tx, rx) = tokio::sync::mpsc::channel(capacity.max(1));
+    tokio::spawn(async move {
+        futures::pin_mut!(source);
+        while let Some(item) = source.next().await {
+            if tx.send(item).await.is_err() {
+                break;
+            }
+        }
+    });
+    tokio_stream::wrappers::ReceiverStream::new(rx)
+}
+
+impl<T: Transport + Clone + 'static> JsonRpcClient<T> {
+    /// Returns a [`Stream`] of finalized blocks, polling the finality tip on an
+    /// interval.
+    ///
+    /// NEAR's JSON-RPC has no push subscriptions, so this polls
+    /// `block(Finality::Final)`, de-duplicates by block height so each new block
+    /// is yielded exactly once, and backfills any skipped heights via
+    /// `block_by_id`. Errors are surfaced as `Err` items rather than
+    /// terminating the stream, so a transient RPC failure does not end the
+    /// subscription.
+    pub fn subscribe_blocks(
+        &self,
+        config: SubscriptionConfig,
+    ) -> impl Stream<Item = Result<BlockView, RpcError>> {
+        let client = self.clone();
+        let poll_interval = config.poll_interval;
+        let inner = stream! {
+            let mut last_height: Option<u64> = None;
+            loop {
+                match client.block(BlockReference::Finality(Finality::Final)).await {
+                    Ok(block) => {
+                        let height = block.header.height;
+                        if last_height.map_or(true, |prev| height > prev) {
+                            // Backfill by following `prev_hash` links rather than
+                            // assuming contiguous heights — NEAR skips heights, so
+                            // iterating the integer range would request blocks that
+                            // never existed and surface spurious `UNKNOWN_BLOCK`s.
+                            let mut backfilled = Vec::new();
+                            let mut backfill_failed = false;
+                            if let Some(prev) = last_height {
+                                let mut cursor = block.header.prev_hash;
+                                loop {
+                                    match client.block_by_id(BlockId::Hash(cursor)).await {
+                                        Ok(parent) => {
+                                            if parent.header.height <= prev {
+                                                break;
+                                            }
+                                            cursor = parent.header.prev_hash;
+                                            backfilled.push(parent);
+                                        }
+                                        Err(err) => {
+                                            yield Err(err);
+                                            backfill_failed = true;
+                                            break;
+                                        }
+                                    }
+                                }
+                            }
+                            // On a failed backfill keep `last_height` so the next
+                            // poll retries it instead of skipping the gap.
+                            if !backfill_failed {
+                                for missed in backfilled.into_iter().rev() {
+                                    yield Ok(missed);
+                                }
+                                last_height = Some(height);
+                                yield Ok(block);
+                            }
+                        }
+                    }
+                    Err(err) => yield Err(err),
+                }
+                tokio::time::sleep(poll_interval).await;
+            }
+        };
+        buffered(inner, config.buffer)
+    }
+
+    /// Returns a [`Stream`] of state changes, polling `EXPERIMENTAL_changes` for
+    /// the finalized block observed at each poll.
+    ///
+    /// Like [`subscribe_blocks`](Self::subscribe_blocks) it de-duplicates by
+    /// block height so a given block's changes are yielded exactly once, and
+    /// surfaces errors as items. Unlike it, skipped blocks are not backfilled:
+    /// the changes of a block finalized between two polls are not fetched.
+    pub fn subscribe_changes(
+        &self,
+        request: RpcStateChangesInBlockByTypeRequest,
+        config: SubscriptionConfig,
+    ) -> impl Stream<Item = Result<RpcStateChangesInBlockByTypeResponse, RpcError>> {
+        let client = self.clone();
+        let poll_interval = config.poll_interval;
+        let inner = stream! {
+            let mut last_height: Option<u64> = None;
+            loop {
+                match client.block(BlockReference::Finality(Finality::Final)).await {
+                    Ok(block) => {
+                        let height = block.header.height;
+                        if last_height.map_or(true, |prev| height > prev) {
+                            last_height = Some(height);
+                            // Re-target the request at the block we just observed,
+                            // otherwise every poll re-queries the caller's original
+                            // reference and races the tip instead of fetching each
+                            // new block's changes.
+                            let mut req = request.clone();
+                            req.block_reference =
+                                BlockReference::BlockId(BlockId::Hash(block.header.hash));
+                            yield client.EXPERIMENTAL_changes(req).await;
+                        }
+                    }
+                    Err(err) => yield Err(err),
+                }
+                tokio::time::sleep(poll_interval).await;
+            }
+        };
+        buffered(inner, config.buffer)
+    }
+}